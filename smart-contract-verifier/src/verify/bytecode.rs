@@ -0,0 +1,367 @@
+use std::collections::BTreeMap;
+
+/// Width, in hex-string characters, of the placeholder a linked library
+/// reference leaves before linking: `__$<34 hex chars>$__`, i.e. 40 hex chars
+/// covering the 20-byte on-chain address.
+const PLACEHOLDER_HEX_LEN: usize = 40;
+
+/// How two bytecodes should be compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchMode {
+    /// Byte-for-byte equality over the whole runtime bytecode.
+    Exact,
+    /// Compare only the executable prefix, discarding the CBOR metadata
+    /// trailer on both sides. This is the "partial match" explorers report.
+    IgnoreMetadata,
+    /// Like [`MatchMode::IgnoreMetadata`], but additionally decode both CBOR
+    /// trailers and diff their `solc` version and source hash.
+    MetadataDiff,
+}
+
+/// A field extracted from a decoded CBOR metadata trailer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Metadata {
+    /// `solc` version string, if present.
+    pub solc: Option<String>,
+    /// Source hash keyed by its algorithm (`ipfs`, `bzzr0`, `bzzr1`).
+    pub source_hash: Option<(String, Vec<u8>)>,
+}
+
+/// Outcome of a deployed-bytecode comparison.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationReport {
+    /// Whether the comparison — under the requested mode — succeeded.
+    pub matched: bool,
+    /// The mode the comparison ran under.
+    pub mode: MatchMode,
+    /// Per-field metadata differences, populated only in
+    /// [`MatchMode::MetadataDiff`]. Empty means the trailers agreed.
+    pub metadata_diff: BTreeMap<String, (Option<String>, Option<String>)>,
+}
+
+impl VerificationReport {
+    fn new(matched: bool, mode: MatchMode) -> Self {
+        Self {
+            matched,
+            mode,
+            metadata_diff: BTreeMap::new(),
+        }
+    }
+}
+
+/// Compares an on-chain `expected` runtime bytecode against a locally
+/// `compiled_runtime` one under `mode`.
+///
+/// Both inputs are hex strings (with or without a `0x` prefix), the form
+/// explorers exchange and the only form in which unlinked library placeholders
+/// (`__$…$__`) appear. Placeholders are first normalized to zero nibbles — so
+/// an unlinked artifact still matches a linked deployment in the executable
+/// region — and the strings are then hex-decoded to raw bytes. solc appends a
+/// CBOR metadata trailer whose final two bytes are the big-endian length `L` of
+/// the preceding CBOR blob, so the trailer occupies the last `L + 2` raw bytes.
+///
+/// A non-hex input decodes to nothing and is reported as a non-match.
+pub fn verify_deployed_bytecode(
+    expected: &str,
+    compiled_runtime: &str,
+    mode: MatchMode,
+) -> VerificationReport {
+    let (Some(expected), Some(compiled)) = (
+        decode_normalized(expected),
+        decode_normalized(compiled_runtime),
+    ) else {
+        return VerificationReport::new(false, mode);
+    };
+
+    match mode {
+        MatchMode::Exact => VerificationReport::new(expected == compiled, mode),
+        MatchMode::IgnoreMetadata => {
+            let a = strip_metadata(&expected);
+            let b = strip_metadata(&compiled);
+            VerificationReport::new(a == b, mode)
+        }
+        MatchMode::MetadataDiff => {
+            let (a_code, a_meta) = split_metadata(&expected);
+            let (b_code, b_meta) = split_metadata(&compiled);
+            let mut report = VerificationReport::new(a_code == b_code, mode);
+            diff_metadata(&mut report.metadata_diff, a_meta.as_deref(), b_meta.as_deref());
+            report
+        }
+    }
+}
+
+/// Normalizes unlinked library placeholders in a hex string and decodes the
+/// result to raw bytes, returning `None` if the normalized string is not valid
+/// hex.
+fn decode_normalized(code: &str) -> Option<Vec<u8>> {
+    let code = code.strip_prefix("0x").unwrap_or(code);
+    hex::decode(normalize_placeholders(code)).ok()
+}
+
+/// Replaces unlinked library placeholders (`__$...$__`) with zero nibbles in a
+/// hex string so they do not defeat a comparison against linked on-chain code.
+fn normalize_placeholders(code: &str) -> String {
+    let bytes = code.as_bytes();
+    let mut out = String::with_capacity(code.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if i + PLACEHOLDER_HEX_LEN <= bytes.len()
+            && &bytes[i..i + 2] == b"__"
+            && &bytes[i + PLACEHOLDER_HEX_LEN - 2..i + PLACEHOLDER_HEX_LEN] == b"__"
+        {
+            for _ in 0..PLACEHOLDER_HEX_LEN {
+                out.push('0');
+            }
+            i += PLACEHOLDER_HEX_LEN;
+        } else {
+            out.push(bytes[i] as char);
+            i += 1;
+        }
+    }
+    out
+}
+
+/// Returns the executable prefix with the CBOR metadata trailer removed.
+fn strip_metadata(code: &[u8]) -> &[u8] {
+    split_metadata(code).0
+}
+
+/// Splits `code` into its executable prefix and the raw CBOR metadata blob.
+///
+/// Returns the whole slice as the prefix (and `None` metadata) when the code
+/// is shorter than two bytes or the declared length does not fit. The blob's
+/// contents are not checked, so code without a trailer whose last two bytes
+/// happen to declare a length that fits is still split.
+fn split_metadata(code: &[u8]) -> (&[u8], Option<&[u8]>) {
+    if code.len() < 2 {
+        return (code, None);
+    }
+    let len = u16::from_be_bytes([code[code.len() - 2], code[code.len() - 1]]) as usize;
+    let trailer = len + 2;
+    if trailer > code.len() {
+        // Declared length overruns the buffer: treat as no trailer.
+        return (code, None);
+    }
+    let split = code.len() - trailer;
+    (&code[..split], Some(&code[split..code.len() - 2]))
+}
+
+/// Decodes both trailers and records differing fields into `diff`.
+fn diff_metadata(
+    diff: &mut BTreeMap<String, (Option<String>, Option<String>)>,
+    expected: Option<&[u8]>,
+    compiled: Option<&[u8]>,
+) {
+    let a = expected.and_then(decode_metadata);
+    let b = compiled.and_then(decode_metadata);
+
+    let a_solc = a.as_ref().and_then(|m| m.solc.clone());
+    let b_solc = b.as_ref().and_then(|m| m.solc.clone());
+    if a_solc != b_solc {
+        diff.insert("solc".to_string(), (a_solc, b_solc));
+    }
+
+    let a_hash = a.as_ref().and_then(format_source_hash);
+    let b_hash = b.as_ref().and_then(format_source_hash);
+    if a_hash != b_hash {
+        diff.insert("source_hash".to_string(), (a_hash, b_hash));
+    }
+}
+
+fn format_source_hash(meta: &Metadata) -> Option<String> {
+    meta.source_hash
+        .as_ref()
+        .map(|(algo, bytes)| format!("{algo}:{}", hex::encode(bytes)))
+}
+
+/// Minimal CBOR decoder for the solc metadata map.
+///
+/// Only the shapes solc emits are handled: a fixed-size map of text-string
+/// keys to either a byte string (`ipfs`/`bzzr0`/`bzzr1`) or a text string
+/// (`solc` as a version, though solc actually encodes it as a 3-byte array —
+/// both forms are accepted).
+fn decode_metadata(blob: &[u8]) -> Option<Metadata> {
+    let mut cur = Cursor::new(blob);
+    let entries = cur.read_map_len()?;
+    let mut meta = Metadata {
+        solc: None,
+        source_hash: None,
+    };
+    for _ in 0..entries {
+        let key = cur.read_text()?;
+        match key.as_str() {
+            "solc" => meta.solc = Some(cur.read_version()?),
+            algo @ ("ipfs" | "bzzr0" | "bzzr1") => {
+                meta.source_hash = Some((algo.to_string(), cur.read_bytes()?.to_vec()));
+            }
+            _ => cur.skip_value()?,
+        }
+    }
+    Some(meta)
+}
+
+/// A tiny forward-only CBOR reader scoped to the solc metadata grammar.
+struct Cursor<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    fn byte(&mut self) -> Option<u8> {
+        let b = *self.buf.get(self.pos)?;
+        self.pos += 1;
+        Some(b)
+    }
+
+    /// Reads the argument encoded in the low 5 bits of a CBOR initial byte.
+    fn read_arg(&mut self, info: u8) -> Option<u64> {
+        match info {
+            0..=23 => Some(info as u64),
+            24 => self.byte().map(u64::from),
+            25 => {
+                let bytes = self.take(2)?;
+                Some(u16::from_be_bytes([bytes[0], bytes[1]]) as u64)
+            }
+            26 => {
+                let bytes = self.take(4)?;
+                Some(u32::from_be_bytes(bytes.try_into().ok()?) as u64)
+            }
+            27 => {
+                let bytes = self.take(8)?;
+                Some(u64::from_be_bytes(bytes.try_into().ok()?))
+            }
+            _ => None,
+        }
+    }
+
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        let slice = self.buf.get(self.pos..self.pos + n)?;
+        self.pos += n;
+        Some(slice)
+    }
+
+    fn read_map_len(&mut self) -> Option<u64> {
+        let b = self.byte()?;
+        if b >> 5 != 5 {
+            return None;
+        }
+        self.read_arg(b & 0x1f)
+    }
+
+    fn read_text(&mut self) -> Option<String> {
+        let b = self.byte()?;
+        if b >> 5 != 3 {
+            return None;
+        }
+        let len = self.read_arg(b & 0x1f)? as usize;
+        let bytes = self.take(len)?;
+        String::from_utf8(bytes.to_vec()).ok()
+    }
+
+    fn read_bytes(&mut self) -> Option<&'a [u8]> {
+        let b = self.byte()?;
+        if b >> 5 != 2 {
+            return None;
+        }
+        let len = self.read_arg(b & 0x1f)? as usize;
+        self.take(len)
+    }
+
+    /// The `solc` value is a 3-element byte array (`[0, 8, 29]`); accept that
+    /// as well as a plain text string for forward compatibility.
+    fn read_version(&mut self) -> Option<String> {
+        let peek = *self.buf.get(self.pos)?;
+        match peek >> 5 {
+            3 => self.read_text(),
+            4 => {
+                let b = self.byte()?;
+                let len = self.read_arg(b & 0x1f)?;
+                let parts: Option<Vec<String>> = (0..len)
+                    .map(|_| {
+                        let vb = self.byte()?;
+                        self.read_arg(vb & 0x1f).map(|n| n.to_string())
+                    })
+                    .collect();
+                Some(parts?.join("."))
+            }
+            2 => self.read_bytes().map(|b| {
+                b.iter()
+                    .map(|n| n.to_string())
+                    .collect::<Vec<_>>()
+                    .join(".")
+            }),
+            _ => None,
+        }
+    }
+
+    /// Skips an arbitrary value, enough to tolerate unknown keys.
+    fn skip_value(&mut self) -> Option<()> {
+        let b = self.byte()?;
+        let major = b >> 5;
+        let arg = self.read_arg(b & 0x1f)?;
+        match major {
+            0 | 1 | 7 => Some(()),
+            2 | 3 => self.take(arg as usize).map(|_| ()),
+            4 => {
+                for _ in 0..arg {
+                    self.skip_value()?;
+                }
+                Some(())
+            }
+            5 => {
+                for _ in 0..arg {
+                    self.skip_value()?;
+                    self.skip_value()?;
+                }
+                Some(())
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_match_requires_full_equality() {
+        assert!(verify_deployed_bytecode("6001", "6001", MatchMode::Exact).matched);
+        assert!(!verify_deployed_bytecode("6001", "6002", MatchMode::Exact).matched);
+    }
+
+    #[test]
+    fn ignore_metadata_compares_only_prefix() {
+        // Same executable prefix `6001`, different metadata trailers.
+        let a = "6001a100410003";
+        let b = "6001b200420003";
+        assert!(!verify_deployed_bytecode(a, b, MatchMode::Exact).matched);
+        assert!(verify_deployed_bytecode(a, b, MatchMode::IgnoreMetadata).matched);
+    }
+
+    #[test]
+    fn declared_length_overrunning_buffer_is_no_trailer() {
+        // Final two bytes `00ff` declare 255 bytes of trailer in a 3-byte code.
+        let code = "6100ff";
+        // Nothing is stripped: the whole code is the prefix, with no metadata.
+        let bytes = hex::decode(code).unwrap();
+        assert_eq!(split_metadata(&bytes), (&bytes[..], None));
+        assert!(verify_deployed_bytecode(code, code, MatchMode::IgnoreMetadata).matched);
+    }
+
+    #[test]
+    fn placeholder_normalized_to_zero_matches_linked_code() {
+        let unlinked = format!("6073{}", "__$0000000000000000000000000000000000$__");
+        let linked = "60730000000000000000000000000000000000000000";
+        assert!(verify_deployed_bytecode(linked, &unlinked, MatchMode::Exact).matched);
+    }
+
+    #[test]
+    fn non_hex_input_does_not_match() {
+        assert!(!verify_deployed_bytecode("zzzz", "zzzz", MatchMode::Exact).matched);
+    }
+}
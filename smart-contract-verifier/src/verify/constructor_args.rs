@@ -0,0 +1,275 @@
+use serde_json::Value;
+
+/// Outcome of matching an on-chain creation transaction against compiled
+/// creation bytecode and recovering its constructor arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstructorArgs {
+    /// Whether the compiled init code, up to its CBOR metadata trailer, is a
+    /// prefix of the observed creation input (i.e. the deployment used this
+    /// contract's creation bytecode). The metadata bytes may differ.
+    pub init_code_matched: bool,
+    /// The decoded constructor arguments, in declaration order. Empty when the
+    /// constructor takes no arguments or the trailing bytes did not decode.
+    pub values: Vec<DecodedArg>,
+    /// Set when init code matched but the trailing bytes did not ABI-decode
+    /// cleanly against the constructor signature — letting callers tell a
+    /// metadata mismatch apart from genuine constructor-argument drift.
+    pub trailing_undecodable: bool,
+}
+
+/// A single recovered constructor argument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedArg {
+    /// Parameter name from the ABI (empty if unnamed).
+    pub name: String,
+    /// Parameter type as written in the ABI, e.g. `saddress` or `suint256`.
+    pub ty: String,
+    /// Human-readable decoded value.
+    pub value: String,
+}
+
+/// Extracts and decodes constructor arguments from an on-chain creation
+/// transaction.
+///
+/// `compiled_creation` is the locally compiled creation (init) bytecode and
+/// `onchain_creation` the `input` of the deployment transaction. Because solc
+/// embeds a CBOR metadata trailer inside the init code, the prefix is matched
+/// *modulo* that trailer: the executable region before the compiled trailer
+/// must agree, while the metadata bytes themselves are allowed to differ. The
+/// bytes past the compiled init length are treated as the ABI-encoded
+/// constructor arguments and decoded against the constructor signature found in
+/// `abi`. Seismic shielded types (`saddress`, `suint256`, `sint*`, `sbool`)
+/// decode as their public base types.
+pub fn extract_constructor_args(
+    compiled_creation: &[u8],
+    onchain_creation: &[u8],
+    abi: &Value,
+) -> ConstructorArgs {
+    let inputs = constructor_inputs(abi);
+
+    let args_start = compiled_creation.len();
+    let code_len = code_region_len(compiled_creation);
+    let init_code_matched = onchain_creation.len() >= args_start
+        && onchain_creation[..code_len] == compiled_creation[..code_len];
+
+    if !init_code_matched {
+        return ConstructorArgs {
+            init_code_matched: false,
+            values: Vec::new(),
+            trailing_undecodable: false,
+        };
+    }
+
+    let encoded = &onchain_creation[args_start..];
+    match decode_args(encoded, &inputs) {
+        Some(values) => ConstructorArgs {
+            init_code_matched: true,
+            values,
+            trailing_undecodable: false,
+        },
+        None => ConstructorArgs {
+            init_code_matched: true,
+            values: Vec::new(),
+            trailing_undecodable: !inputs.is_empty() || !encoded.is_empty(),
+        },
+    }
+}
+
+/// Index at which `code`'s trailing CBOR metadata begins, or `code.len()` when
+/// the code is shorter than two bytes or the declared length does not fit.
+///
+/// The final two bytes are the big-endian length `L` of the preceding CBOR
+/// blob, so the trailer occupies the last `L + 2` bytes. The blob's contents
+/// are not checked.
+fn code_region_len(code: &[u8]) -> usize {
+    if code.len() < 2 {
+        return code.len();
+    }
+    let len = u16::from_be_bytes([code[code.len() - 2], code[code.len() - 1]]) as usize;
+    let trailer = len + 2;
+    if trailer > code.len() {
+        code.len()
+    } else {
+        code.len() - trailer
+    }
+}
+
+/// The `(name, type)` pairs of the ABI's constructor, or empty if absent.
+fn constructor_inputs(abi: &Value) -> Vec<(String, String)> {
+    let entries = match abi {
+        Value::Array(entries) => entries,
+        // Some artifacts nest the ABI under an `abi` key.
+        Value::Object(map) => match map.get("abi").and_then(Value::as_array) {
+            Some(entries) => entries,
+            None => return Vec::new(),
+        },
+        _ => return Vec::new(),
+    };
+
+    for entry in entries {
+        if entry.get("type").and_then(Value::as_str) == Some("constructor") {
+            if let Some(inputs) = entry.get("inputs").and_then(Value::as_array) {
+                return inputs
+                    .iter()
+                    .map(|p| {
+                        (
+                            p.get("name")
+                                .and_then(Value::as_str)
+                                .unwrap_or_default()
+                                .to_string(),
+                            p.get("type")
+                                .and_then(Value::as_str)
+                                .unwrap_or_default()
+                                .to_string(),
+                        )
+                    })
+                    .collect();
+            }
+        }
+    }
+    Vec::new()
+}
+
+/// Decodes the head-encoded argument region, returning `None` if any word is
+/// missing or a value is malformed.
+///
+/// Only the statically-sized types constructors overwhelmingly use are
+/// supported: the shielded/public address, unsigned/signed integer, boolean
+/// and fixed-bytes families. Each occupies one 32-byte word.
+fn decode_args(encoded: &[u8], inputs: &[(String, String)]) -> Option<Vec<DecodedArg>> {
+    // Every supported type is a single static word; the encoded region must be
+    // exactly that wide to decode cleanly.
+    if encoded.len() != inputs.len() * 32 {
+        return None;
+    }
+
+    let mut values = Vec::with_capacity(inputs.len());
+    for (i, (name, ty)) in inputs.iter().enumerate() {
+        let word = &encoded[i * 32..i * 32 + 32];
+        let value = decode_word(&canonical_type(ty), word)?;
+        values.push(DecodedArg {
+            name: name.clone(),
+            ty: ty.clone(),
+            value,
+        });
+    }
+    Some(values)
+}
+
+/// Maps a Seismic shielded type to the public base type it ABI-encodes as.
+fn canonical_type(ty: &str) -> String {
+    match ty {
+        "saddress" => "address".to_string(),
+        "sbool" => "bool".to_string(),
+        other => {
+            if let Some(rest) = other.strip_prefix("suint") {
+                format!("uint{rest}")
+            } else if let Some(rest) = other.strip_prefix("sint") {
+                format!("int{rest}")
+            } else {
+                other.to_string()
+            }
+        }
+    }
+}
+
+/// Decodes one 32-byte ABI word as `ty`.
+fn decode_word(ty: &str, word: &[u8]) -> Option<String> {
+    if ty == "address" {
+        return Some(format!("0x{}", hex::encode(&word[12..32])));
+    }
+    if ty == "bool" {
+        return match word.last() {
+            Some(0) if word[..31].iter().all(|&b| b == 0) => Some("false".to_string()),
+            Some(1) if word[..31].iter().all(|&b| b == 0) => Some("true".to_string()),
+            _ => None,
+        };
+    }
+    if ty.starts_with("uint") {
+        let trimmed = hex::encode(word);
+        let trimmed = trimmed.trim_start_matches('0');
+        let trimmed = if trimmed.is_empty() { "0" } else { trimmed };
+        return Some(format!("0x{trimmed}"));
+    }
+    if ty.starts_with("int") {
+        // Preserve the full two's-complement word; callers decode sign as needed.
+        return Some(format!("0x{}", hex::encode(word)));
+    }
+    if let Some(n) = ty.strip_prefix("bytes").and_then(|s| s.parse::<usize>().ok()) {
+        if (1..=32).contains(&n) {
+            return Some(format!("0x{}", hex::encode(&word[..n])));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn shielded_abi() -> Value {
+        json!([{
+            "type": "constructor",
+            "inputs": [
+                { "name": "_owner", "type": "saddress" },
+                { "name": "_initialBalance", "type": "suint256" }
+            ]
+        }])
+    }
+
+    // Code region `6080`, a 3-byte CBOR blob and its `0003` length.
+    const COMPILED: &str = "6080a100410003";
+
+    fn owner_word() -> String {
+        format!("{}01", "00".repeat(31))
+    }
+
+    fn balance_word() -> String {
+        format!("{}ff", "00".repeat(31))
+    }
+
+    #[test]
+    fn recovers_args_across_differing_metadata() {
+        // Same code region, different metadata trailer, then the encoded args.
+        let onchain = format!("6080b200420003{}{}", owner_word(), balance_word());
+        let result = extract_constructor_args(
+            &hex::decode(COMPILED).unwrap(),
+            &hex::decode(onchain).unwrap(),
+            &shielded_abi(),
+        );
+        assert!(result.init_code_matched);
+        assert!(!result.trailing_undecodable);
+        assert_eq!(result.values.len(), 2);
+        assert_eq!(
+            result.values[0].value,
+            "0x0000000000000000000000000000000000000001"
+        );
+        assert_eq!(result.values[1].value, "0xff");
+    }
+
+    #[test]
+    fn divergent_code_region_is_not_matched() {
+        let onchain = format!("7080b200420003{}{}", owner_word(), balance_word());
+        let result = extract_constructor_args(
+            &hex::decode(COMPILED).unwrap(),
+            &hex::decode(onchain).unwrap(),
+            &shielded_abi(),
+        );
+        assert!(!result.init_code_matched);
+    }
+
+    #[test]
+    fn trailing_bytes_that_do_not_fit_are_flagged() {
+        // One trailing byte: cannot fill the two expected argument words.
+        let onchain = "6080b20042000300";
+        let result = extract_constructor_args(
+            &hex::decode(COMPILED).unwrap(),
+            &hex::decode(onchain).unwrap(),
+            &shielded_abi(),
+        );
+        assert!(result.init_code_matched);
+        assert!(result.trailing_undecodable);
+        assert!(result.values.is_empty());
+    }
+}
@@ -0,0 +1,158 @@
+use foundry_compilers_new::{artifacts::SolcInput, solc::Solc};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// A single diagnostic emitted by solc in the `errors` array.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Diagnostic {
+    /// `error`, `warning` or `info`.
+    pub severity: String,
+    /// Diagnostic category, e.g. `TypeError` or `DeclarationError`.
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    /// The raw message.
+    pub message: String,
+    /// The pretty, source-annotated rendering solc produces.
+    #[serde(rename = "formattedMessage")]
+    pub formatted_message: Option<String>,
+    /// Numeric solc error code, when present.
+    #[serde(rename = "errorCode")]
+    pub error_code: Option<String>,
+    /// Location in the source the diagnostic refers to.
+    #[serde(rename = "sourceLocation")]
+    pub source_location: Option<SourceLocation>,
+}
+
+impl Diagnostic {
+    /// Whether this diagnostic is an `error` (as opposed to a warning/info).
+    pub fn is_error(&self) -> bool {
+        self.severity == "error"
+    }
+}
+
+/// A byte range within a source file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SourceLocation {
+    /// The source unit path.
+    pub file: Option<String>,
+    /// Start byte offset, or `-1` when unknown.
+    pub start: Option<i64>,
+    /// End byte offset, or `-1` when unknown.
+    pub end: Option<i64>,
+}
+
+/// Typed view of a solc Standard JSON *output* object.
+///
+/// Replaces scraping the raw `errors` array for `severity == "error"`: each
+/// diagnostic deserializes into a [`Diagnostic`], and the generated
+/// `contracts`/`sources` maps are preserved so callers get programmatic access
+/// to warnings-only versus error states and to Seismic-specific type errors
+/// (e.g. misuse of `saddress`/`suint256`) without scraping log lines.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct CompilationOutcome {
+    /// Every diagnostic solc reported; empty when the output had no `errors`.
+    #[serde(default)]
+    pub errors: Vec<Diagnostic>,
+    /// Generated contracts, keyed by source file then contract name.
+    #[serde(default)]
+    pub contracts: BTreeMap<String, serde_json::Value>,
+    /// Per-source output (ASTs, identifiers), keyed by source file.
+    #[serde(default)]
+    pub sources: BTreeMap<String, serde_json::Value>,
+}
+
+impl CompilationOutcome {
+    /// Parses a raw solc Standard JSON output buffer.
+    pub fn parse(output: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(output)
+    }
+
+    /// Whether any diagnostic is an error. A successful compile with warnings
+    /// returns `false`.
+    pub fn has_errors(&self) -> bool {
+        self.errors.iter().any(Diagnostic::is_error)
+    }
+
+    /// The diagnostics that are errors.
+    pub fn errors(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.errors.iter().filter(|d| d.is_error())
+    }
+
+    /// The diagnostics that are not errors (warnings and info).
+    pub fn warnings(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.errors.iter().filter(|d| !d.is_error())
+    }
+}
+
+/// Compiles `input` with `solc` and returns the typed [`CompilationOutcome`].
+///
+/// This is the library entry point callers use instead of scraping stdout: it
+/// neither prints nor flattens failures into `Box<dyn Error>`, so a
+/// warnings-only build and an error build are both `Ok` and distinguished via
+/// [`CompilationOutcome::has_errors`], while only process/parse failures surface
+/// as [`CompileError`].
+pub async fn compile(solc: &Solc, input: &SolcInput) -> Result<CompilationOutcome, CompileError> {
+    let output = solc
+        .async_compile_output(input)
+        .await
+        .map_err(CompileError::Compile)?;
+    CompilationOutcome::parse(&output).map_err(CompileError::Deserialize)
+}
+
+/// Errors raised while driving solc or parsing its output.
+#[derive(Debug, thiserror::Error)]
+pub enum CompileError {
+    #[error("solc compilation failed")]
+    Compile(#[source] foundry_compilers_new::error::SolcError),
+    #[error("failed to parse solc output")]
+    Deserialize(#[source] serde_json::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTPUT: &str = r#"{
+        "errors": [
+            {
+                "severity": "warning",
+                "type": "Warning",
+                "message": "Unused local variable.",
+                "formattedMessage": "Warning: Unused local variable.",
+                "errorCode": "2072"
+            },
+            {
+                "severity": "error",
+                "type": "TypeError",
+                "message": "saddress is not convertible to uint256.",
+                "sourceLocation": { "file": "Test.sol", "start": 10, "end": 20 }
+            }
+        ],
+        "contracts": { "Test.sol": { "ShieldedWallet": {} } },
+        "sources": { "Test.sol": {} }
+    }"#;
+
+    #[test]
+    fn has_errors_distinguishes_errors_from_warnings() {
+        let outcome = CompilationOutcome::parse(OUTPUT.as_bytes()).unwrap();
+        assert!(outcome.has_errors());
+        assert_eq!(outcome.errors().count(), 1);
+        assert_eq!(outcome.warnings().count(), 1);
+        assert_eq!(outcome.errors().next().unwrap().ty.as_deref(), Some("TypeError"));
+    }
+
+    #[test]
+    fn warnings_only_output_is_not_an_error() {
+        let output = r#"{ "errors": [ { "severity": "warning", "message": "x" } ] }"#;
+        let outcome = CompilationOutcome::parse(output.as_bytes()).unwrap();
+        assert!(!outcome.has_errors());
+        assert_eq!(outcome.warnings().count(), 1);
+    }
+
+    #[test]
+    fn contracts_and_sources_are_preserved() {
+        let outcome = CompilationOutcome::parse(OUTPUT.as_bytes()).unwrap();
+        assert!(outcome.contracts.contains_key("Test.sol"));
+        assert!(outcome.sources.contains_key("Test.sol"));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod bytecode;
+pub mod constructor_args;
+pub mod diagnostics;
+pub mod project;
+pub mod solc_manager;
+pub mod standard_json;
@@ -0,0 +1,385 @@
+use foundry_compilers_new::{artifacts, solc::Solc, solc::SolcLanguage};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+/// An `import` remapping of the form `prefix=target`.
+///
+/// Any import path starting with `prefix` is rewritten to `target` before the
+/// file is located on disk, mirroring the `@openzeppelin/=lib/openzeppelin/`
+/// style explorers and Foundry exchange.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Remapping {
+    /// The import prefix that triggers the rewrite.
+    pub prefix: String,
+    /// The on-disk (or virtual) path the prefix maps to.
+    pub target: String,
+}
+
+impl Remapping {
+    /// Parses a single `prefix=target` remapping.
+    pub fn parse(raw: &str) -> Result<Self, ProjectError> {
+        let (prefix, target) = raw
+            .split_once('=')
+            .ok_or_else(|| ProjectError::Remapping(raw.to_string()))?;
+        if prefix.is_empty() {
+            return Err(ProjectError::Remapping(raw.to_string()));
+        }
+        Ok(Self {
+            prefix: prefix.to_string(),
+            target: target.to_string(),
+        })
+    }
+
+    /// Applies the remapping to `import_path`, returning the rewritten path when
+    /// the prefix matches.
+    fn apply(&self, import_path: &str) -> Option<String> {
+        import_path
+            .strip_prefix(&self.prefix)
+            .map(|rest| format!("{}{rest}", self.target))
+    }
+}
+
+/// Layout of a Solidity project: where first-party sources live, where library
+/// dependencies are rooted, and how import prefixes are remapped.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectPaths {
+    /// Root directory holding the project's own sources.
+    pub sources: PathBuf,
+    /// Additional roots searched when resolving library imports.
+    pub libs: Vec<PathBuf>,
+    /// Import remappings applied before a file is located.
+    pub remappings: Vec<Remapping>,
+}
+
+impl ProjectPaths {
+    /// Creates a layout rooted at `sources` with no libs or remappings.
+    pub fn new(sources: impl Into<PathBuf>) -> Self {
+        Self {
+            sources: sources.into(),
+            libs: Vec::new(),
+            remappings: Vec::new(),
+        }
+    }
+
+    /// Adds a library search root.
+    pub fn lib(mut self, lib: impl Into<PathBuf>) -> Self {
+        self.libs.push(lib.into());
+        self
+    }
+
+    /// Adds a `prefix=target` remapping.
+    pub fn remapping(mut self, remapping: Remapping) -> Self {
+        self.remappings.push(remapping);
+        self
+    }
+}
+
+/// A Solidity project assembled from a root directory or a set of virtual
+/// sources, ready to be compiled as a single `SolcInput`.
+///
+/// The project walks its source tree, follows `import` statements across files
+/// and libraries — applying [`Remapping`]s along the way — and collects every
+/// reachable file into a complete [`artifacts::Sources`] map. Compiling returns
+/// per-contract artifacts keyed by `file:ContractName`.
+#[derive(Debug, Clone)]
+pub struct Project {
+    paths: ProjectPaths,
+    /// Virtual sources injected directly, keyed by their import path. Files not
+    /// present here are read from disk under `sources`/`libs`.
+    virtual_sources: BTreeMap<String, String>,
+}
+
+impl Project {
+    /// Creates a project reading sources from disk according to `paths`.
+    pub fn new(paths: ProjectPaths) -> Self {
+        Self {
+            paths,
+            virtual_sources: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a project backed entirely by an in-memory map of
+    /// `virtual path → source`, resolving imports against that map.
+    pub fn from_sources(sources: BTreeMap<String, String>) -> Self {
+        Self {
+            paths: ProjectPaths::default(),
+            virtual_sources: sources,
+        }
+    }
+
+    /// Assembles the complete [`artifacts::Sources`] map, following imports from
+    /// every entry point and pulling in each reachable dependency exactly once.
+    pub fn collect_sources(&self) -> Result<artifacts::Sources, ProjectError> {
+        let mut collected: BTreeMap<PathBuf, artifacts::Source> = BTreeMap::new();
+        let mut queue: Vec<String> = self.entry_points()?;
+
+        while let Some(import_path) = queue.pop() {
+            // Keyed by the path as imported (before remapping), which is the
+            // source unit name solc looks up when it meets the import.
+            let key = PathBuf::from(&import_path);
+            if collected.contains_key(&key) {
+                continue;
+            }
+            let resolved = self.apply_remappings(&import_path);
+            let content = self.read_source(&resolved)?;
+            for import in parse_imports(&content) {
+                let next = resolve_relative(&import_path, &import);
+                queue.push(next);
+            }
+            collected.insert(key, artifacts::Source::new(content));
+        }
+
+        Ok(artifacts::Sources(collected))
+    }
+
+    /// Compiles the assembled project with `solc` and returns per-contract
+    /// artifacts keyed by `file:ContractName`.
+    pub async fn compile(
+        &self,
+        solc: &Solc,
+    ) -> Result<BTreeMap<String, serde_json::Value>, ProjectError> {
+        let sources = self.collect_sources()?;
+        let input = artifacts::SolcInput {
+            language: SolcLanguage::Solidity,
+            sources,
+            // Every reachable file is added to `sources` during
+            // `collect_sources` under the path it is imported by, so solc
+            // needs no further remapping settings.
+            settings: artifacts::Settings {
+                evm_version: None,
+                ..Default::default()
+            },
+        };
+
+        let output = solc
+            .async_compile_output(&input)
+            .await
+            .map_err(ProjectError::Compile)?;
+        let json: serde_json::Value =
+            serde_json::from_slice(&output).map_err(ProjectError::Deserialize)?;
+
+        let mut artifacts = BTreeMap::new();
+        if let Some(contracts) = json.get("contracts").and_then(|c| c.as_object()) {
+            for (file, names) in contracts {
+                if let Some(names) = names.as_object() {
+                    for (name, artifact) in names {
+                        artifacts.insert(format!("{file}:{name}"), artifact.clone());
+                    }
+                }
+            }
+        }
+        Ok(artifacts)
+    }
+
+    /// The import paths that seed resolution: every virtual source, or every
+    /// `.sol` file found by walking the `sources` root.
+    fn entry_points(&self) -> Result<Vec<String>, ProjectError> {
+        if !self.virtual_sources.is_empty() {
+            return Ok(self.virtual_sources.keys().cloned().collect());
+        }
+        let mut entries = Vec::new();
+        walk_sol_files(&self.paths.sources, &mut entries)?;
+        Ok(entries
+            .into_iter()
+            .map(|p| p.to_string_lossy().into_owned())
+            .collect())
+    }
+
+    /// Rewrites `import_path` through the first matching remapping.
+    fn apply_remappings(&self, import_path: &str) -> String {
+        for remapping in &self.paths.remappings {
+            if let Some(rewritten) = remapping.apply(import_path) {
+                return rewritten;
+            }
+        }
+        import_path.to_string()
+    }
+
+    /// Loads the source for a resolved import path, from the virtual map first
+    /// and then from disk under `sources` and each `libs` root.
+    fn read_source(&self, resolved: &str) -> Result<String, ProjectError> {
+        if let Some(content) = self.virtual_sources.get(resolved) {
+            return Ok(content.clone());
+        }
+        let direct = Path::new(resolved);
+        if direct.is_file() {
+            return std::fs::read_to_string(direct).map_err(ProjectError::Io);
+        }
+        for root in std::iter::once(&self.paths.sources).chain(self.paths.libs.iter()) {
+            let candidate = root.join(resolved);
+            if candidate.is_file() {
+                return std::fs::read_to_string(candidate).map_err(ProjectError::Io);
+            }
+        }
+        Err(ProjectError::Unresolved(resolved.to_string()))
+    }
+}
+
+/// Extracts the imported paths from the `import` statements in `source`.
+///
+/// Handles every import form regardless of line layout — `import "path";`,
+/// `import "path" as X;`, the single- and multi-line
+/// `import { A, B } from "path";`, and imports that do not start their own
+/// line — by locating each `import` keyword and taking the first quoted string
+/// up to the statement-terminating `;`.
+fn parse_imports(source: &str) -> Vec<String> {
+    let mut imports = Vec::new();
+    let bytes = source.as_bytes();
+    let mut cursor = 0;
+    while let Some(offset) = source[cursor..].find("import") {
+        let start = cursor + offset;
+        let end = start + "import".len();
+        cursor = end;
+
+        // Require word boundaries so `important` / `reimport` do not match.
+        let preceded_ok = start == 0 || !is_ident_byte(bytes[start - 1]);
+        let followed_ok = end >= bytes.len()
+            || matches!(bytes[end], b' ' | b'\t' | b'\n' | b'\r' | b'{' | b'"');
+        if !preceded_ok || !followed_ok {
+            continue;
+        }
+
+        // The path is the first quoted string before the statement terminator,
+        // which may be several lines away for brace-list imports.
+        let stmt_end = source[end..]
+            .find(';')
+            .map(|p| end + p)
+            .unwrap_or(source.len());
+        if let Some(path) = first_quoted(&source[end..stmt_end]) {
+            imports.push(path);
+        }
+    }
+    imports
+}
+
+/// Whether `b` can appear inside a Solidity identifier.
+fn is_ident_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'$'
+}
+
+/// Returns the contents of the first single- or double-quoted string in `text`.
+fn first_quoted(text: &str) -> Option<String> {
+    let open = text.find(|c| c == '"' || c == '\'')?;
+    let quote = if text.as_bytes()[open] == b'"' { '"' } else { '\'' };
+    let start = open + 1;
+    let end = text[start..].find(quote)? + start;
+    Some(text[start..end].to_string())
+}
+
+/// Resolves a relative import (`./x`, `../x`) against the importing file; other
+/// imports (library/remapped paths) are returned unchanged.
+fn resolve_relative(importer: &str, import: &str) -> String {
+    if !import.starts_with('.') {
+        return import.to_string();
+    }
+    let base = Path::new(importer).parent().unwrap_or(Path::new(""));
+    normalize(&base.join(import)).to_string_lossy().into_owned()
+}
+
+/// Collapses `.` and `..` segments in a path without touching the filesystem.
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for comp in path.components() {
+        match comp {
+            std::path::Component::ParentDir => {
+                out.pop();
+            }
+            std::path::Component::CurDir => {}
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Recursively collects every `.sol` file under `dir` into `out`.
+fn walk_sol_files(dir: &Path, out: &mut Vec<PathBuf>) -> Result<(), ProjectError> {
+    for entry in std::fs::read_dir(dir).map_err(ProjectError::Io)? {
+        let entry = entry.map_err(ProjectError::Io)?;
+        let path = entry.path();
+        if path.is_dir() {
+            walk_sol_files(&path, out)?;
+        } else if path.extension().is_some_and(|ext| ext == "sol") {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Errors raised while assembling or compiling a multi-file project.
+#[derive(Debug, thiserror::Error)]
+pub enum ProjectError {
+    #[error("invalid remapping (expected `prefix=target`): {0}")]
+    Remapping(String),
+    #[error("could not resolve import `{0}` in sources or libs")]
+    Unresolved(String),
+    #[error("filesystem error while reading project sources")]
+    Io(#[source] std::io::Error),
+    #[error("solc compilation failed")]
+    Compile(#[source] foundry_compilers_new::error::SolcError),
+    #[error("failed to deserialize compiler output")]
+    Deserialize(#[source] serde_json::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remapping_parses_and_applies() {
+        let r = Remapping::parse("@oz/=lib/oz/").unwrap();
+        assert_eq!(r.prefix, "@oz/");
+        assert_eq!(r.target, "lib/oz/");
+        assert_eq!(r.apply("@oz/token/ERC20.sol").as_deref(), Some("lib/oz/token/ERC20.sol"));
+        assert!(r.apply("./Local.sol").is_none());
+    }
+
+    #[test]
+    fn remapping_rejects_malformed_input() {
+        assert!(Remapping::parse("no-equals").is_err());
+        assert!(Remapping::parse("=target").is_err());
+    }
+
+    #[test]
+    fn parse_imports_handles_every_form() {
+        let source = r#"
+            pragma solidity ^0.8.0;
+            import "./A.sol";
+            import "@oz/token/ERC20.sol" as Erc;
+            import {
+                Foo,
+                Bar
+            } from "./nested/B.sol";
+            contract C {} // reimport should not match
+        "#;
+        let imports = parse_imports(source);
+        assert_eq!(
+            imports,
+            vec![
+                "./A.sol".to_string(),
+                "@oz/token/ERC20.sol".to_string(),
+                "./nested/B.sol".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn relative_imports_resolve_against_importer() {
+        assert_eq!(resolve_relative("src/a/Main.sol", "./Lib.sol"), "src/a/Lib.sol");
+        assert_eq!(resolve_relative("src/a/Main.sol", "../b/Lib.sol"), "src/b/Lib.sol");
+        // Non-relative imports (remapped/library) pass through untouched.
+        assert_eq!(resolve_relative("src/a/Main.sol", "@oz/X.sol"), "@oz/X.sol");
+    }
+
+    #[test]
+    fn collect_sources_follows_virtual_import_graph() {
+        let mut sources = BTreeMap::new();
+        sources.insert("Main.sol".to_string(), "import \"./Lib.sol\";".to_string());
+        sources.insert("Lib.sol".to_string(), "contract Lib {}".to_string());
+        let project = Project::from_sources(sources);
+        let collected = project.collect_sources().unwrap();
+        assert_eq!(collected.0.len(), 2);
+        assert!(collected.0.contains_key(&PathBuf::from("Lib.sol")));
+    }
+}
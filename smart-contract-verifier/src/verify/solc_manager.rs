@@ -0,0 +1,287 @@
+use foundry_compilers_new::solc::Solc;
+use semver::Version;
+use sha2::{Digest, Sha256};
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+/// Base URL of the Seismic compiler binary distribution.
+///
+/// The layout mirrors the upstream `solc-bin` repository: a `bin/` directory
+/// holding every native `solc-linux-amd64-v<version>` build plus the
+/// `bin/list.txt` manifest enumerating them and a `bin/list.json` carrying the
+/// per-build `sha256` checksums.
+const SEISMIC_SOLC_BASE: &str = "https://seismic-solc-bin.seismic.systems/linux-amd64";
+
+/// File-name prefix of a native linux/amd64 `solc` build in the manifest, e.g.
+/// `solc-linux-amd64-v0.8.29+commit.d4b8c7ae`.
+const NATIVE_PREFIX: &str = "solc-linux-amd64-v";
+
+/// Resolves, downloads and caches Seismic `solc` binaries by short version.
+///
+/// Callers only know a contract was compiled with e.g. `0.8.29`; the on-disk
+/// binary, however, is named with full build metadata
+/// (`0.8.29+commit.d4b8c7ae`). `SolcManager` bridges that gap: it reads the
+/// upstream `list.txt` manifest to recover the build-metadata semver, then
+/// downloads the matching binary into a per-version cache directory so the
+/// next lookup is a no-op.
+#[derive(Debug, Clone)]
+pub struct SolcManager {
+    /// Root of the per-version binary cache (`<root>/v<version>/solc`).
+    cache_dir: PathBuf,
+    /// Base URL the manifest and binaries are fetched from.
+    base_url: String,
+}
+
+impl Default for SolcManager {
+    fn default() -> Self {
+        Self::new(default_cache_dir())
+    }
+}
+
+impl SolcManager {
+    /// Creates a manager that caches binaries under `cache_dir`.
+    pub fn new(cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            cache_dir: cache_dir.into(),
+            base_url: SEISMIC_SOLC_BASE.to_string(),
+        }
+    }
+
+    /// Overrides the distribution base URL (chiefly for tests/mirrors).
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Resolves `short_version` (e.g. `0.8.29`) to a ready-to-use [`Solc`].
+    ///
+    /// The matching binary is downloaded and cached on first use; subsequent
+    /// calls for the same version reuse the cached file.
+    pub async fn get_or_install(&self, short_version: &Version) -> Result<Solc, SolcManagerError> {
+        let full_version = self.resolve_version(short_version).await?;
+        let binary = self.install(&full_version).await?;
+        Ok(Solc::new_with_version(binary, full_version))
+    }
+
+    /// Recovers the full build-metadata semver for a short version by parsing
+    /// the upstream `list.txt` manifest.
+    ///
+    /// `nightly` builds are skipped; the first stable
+    /// `solc-linux-amd64-v<version>` entry whose core `major.minor.patch`
+    /// matches is returned.
+    pub async fn resolve_version(
+        &self,
+        short_version: &Version,
+    ) -> Result<Version, SolcManagerError> {
+        let url = format!("{}/bin/list.txt", self.base_url);
+        let list = reqwest::get(&url)
+            .await
+            .map_err(SolcManagerError::Fetch)?
+            .error_for_status()
+            .map_err(SolcManagerError::Fetch)?
+            .text()
+            .await
+            .map_err(SolcManagerError::Fetch)?;
+
+        parse_list(&list, short_version)
+            .ok_or_else(|| SolcManagerError::VersionNotFound(short_version.clone()))
+    }
+
+    /// Path the binary for `full_version` is (or will be) cached at.
+    pub fn binary_path(&self, full_version: &Version) -> PathBuf {
+        self.cache_dir.join(format!("v{full_version}")).join("solc")
+    }
+
+    /// Downloads the native binary for `full_version` into the cache if absent,
+    /// verifies its `sha256` against the `bin/list.json` manifest, and returns
+    /// its path.
+    async fn install(&self, full_version: &Version) -> Result<PathBuf, SolcManagerError> {
+        let target = self.binary_path(full_version);
+        if target.exists() {
+            return Ok(target);
+        }
+
+        let file_name = format!("{NATIVE_PREFIX}{full_version}");
+        let url = format!("{}/bin/{file_name}", self.base_url);
+        let bytes = reqwest::get(&url)
+            .await
+            .map_err(SolcManagerError::Fetch)?
+            .error_for_status()
+            .map_err(SolcManagerError::Fetch)?
+            .bytes()
+            .await
+            .map_err(SolcManagerError::Fetch)?;
+
+        let expected = self.fetch_checksum(full_version).await?;
+        verify_checksum(&bytes, &expected)?;
+
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent).map_err(SolcManagerError::Io)?;
+        }
+
+        // Write to a temporary sibling first so a concurrent reader never sees a
+        // half-written binary, then atomically rename into place.
+        let tmp = target.with_extension("download");
+        {
+            let mut file = std::fs::File::create(&tmp).map_err(SolcManagerError::Io)?;
+            file.write_all(&bytes).map_err(SolcManagerError::Io)?;
+            file.flush().map_err(SolcManagerError::Io)?;
+        }
+        set_executable(&tmp)?;
+        std::fs::rename(&tmp, &target).map_err(SolcManagerError::Io)?;
+
+        Ok(target)
+    }
+
+    /// Fetches the expected `sha256` checksum for `full_version` from the
+    /// `bin/list.json` manifest.
+    async fn fetch_checksum(&self, full_version: &Version) -> Result<String, SolcManagerError> {
+        let url = format!("{}/bin/list.json", self.base_url);
+        let manifest = reqwest::get(&url)
+            .await
+            .map_err(SolcManagerError::Fetch)?
+            .error_for_status()
+            .map_err(SolcManagerError::Fetch)?
+            .text()
+            .await
+            .map_err(SolcManagerError::Fetch)?;
+
+        parse_checksum(&manifest, full_version)
+            .ok_or_else(|| SolcManagerError::VersionNotFound(full_version.clone()))
+    }
+}
+
+/// Extracts the full build-metadata version matching `short_version` from a
+/// `list.txt` manifest body.
+fn parse_list(list: &str, short_version: &Version) -> Option<Version> {
+    for line in list.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.contains("nightly") {
+            continue;
+        }
+        // Lines name the native build, e.g. `solc-linux-amd64-v0.8.29+commit.d4b8c7ae`.
+        let Some(raw) = line.strip_prefix(NATIVE_PREFIX) else {
+            continue;
+        };
+        let Ok(version) = Version::parse(raw) else {
+            continue;
+        };
+        if version.major == short_version.major
+            && version.minor == short_version.minor
+            && version.patch == short_version.patch
+        {
+            return Some(version);
+        }
+    }
+    None
+}
+
+/// Extracts the `sha256` checksum for the build whose `longVersion` matches
+/// `full_version` from a `list.json` manifest body.
+fn parse_checksum(manifest: &str, full_version: &Version) -> Option<String> {
+    let json: serde_json::Value = serde_json::from_str(manifest).ok()?;
+    let builds = json.get("builds")?.as_array()?;
+    let wanted = full_version.to_string();
+    for build in builds {
+        if build.get("longVersion").and_then(|v| v.as_str()) == Some(wanted.as_str()) {
+            return build
+                .get("sha256")
+                .and_then(|v| v.as_str())
+                .map(|s| s.trim_start_matches("0x").to_ascii_lowercase());
+        }
+    }
+    None
+}
+
+/// Verifies that the SHA-256 of `bytes` equals the `expected` hex digest.
+fn verify_checksum(bytes: &[u8], expected: &str) -> Result<(), SolcManagerError> {
+    let actual = hex::encode(Sha256::digest(bytes));
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(SolcManagerError::ChecksumMismatch {
+            expected: expected.to_string(),
+            actual,
+        })
+    }
+}
+
+/// Default cache root, `/tmp/solidity-compilers`.
+fn default_cache_dir() -> PathBuf {
+    PathBuf::from("/tmp/solidity-compilers")
+}
+
+#[cfg(unix)]
+fn set_executable(path: &Path) -> Result<(), SolcManagerError> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut perms = std::fs::metadata(path)
+        .map_err(SolcManagerError::Io)?
+        .permissions();
+    perms.set_mode(0o755);
+    std::fs::set_permissions(path, perms).map_err(SolcManagerError::Io)
+}
+
+#[cfg(not(unix))]
+fn set_executable(_path: &Path) -> Result<(), SolcManagerError> {
+    Ok(())
+}
+
+/// Errors raised while resolving or installing a Seismic `solc` binary.
+#[derive(Debug, thiserror::Error)]
+pub enum SolcManagerError {
+    #[error("failed to fetch Seismic compiler list or binary")]
+    Fetch(#[source] reqwest::Error),
+    #[error("no Seismic solc build matching version {0}")]
+    VersionNotFound(Version),
+    #[error("sha256 mismatch for downloaded solc (expected {expected}, got {actual})")]
+    ChecksumMismatch { expected: String, actual: String },
+    #[error("filesystem error while caching solc binary")]
+    Io(#[source] std::io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_list_recovers_full_version_and_skips_nightly() {
+        let list = "\
+solc-linux-amd64-v0.8.28+commit.7893614a
+solc-linux-amd64-v0.8.29-nightly.2024.1.1+commit.deadbeef
+solc-linux-amd64-v0.8.29+commit.d4b8c7ae
+";
+        let full = parse_list(&list, &Version::new(0, 8, 29)).unwrap();
+        assert_eq!(full.to_string(), "0.8.29+commit.d4b8c7ae");
+    }
+
+    #[test]
+    fn parse_list_returns_none_for_missing_version() {
+        let list = "solc-linux-amd64-v0.8.28+commit.7893614a\n";
+        assert!(parse_list(&list, &Version::new(0, 8, 29)).is_none());
+    }
+
+    #[test]
+    fn parse_checksum_matches_on_long_version() {
+        let manifest = r#"{
+            "builds": [
+                { "longVersion": "0.8.28+commit.7893614a", "sha256": "0xaaaa" },
+                { "longVersion": "0.8.29+commit.d4b8c7ae", "sha256": "0xBBBB" }
+            ]
+        }"#;
+        let version = Version::parse("0.8.29+commit.d4b8c7ae").unwrap();
+        assert_eq!(parse_checksum(manifest, &version).as_deref(), Some("bbbb"));
+    }
+
+    #[test]
+    fn verify_checksum_detects_mismatch() {
+        // sha256("") = e3b0c442...
+        let empty = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+        assert!(verify_checksum(b"", empty).is_ok());
+        assert!(matches!(
+            verify_checksum(b"not empty", empty),
+            Err(SolcManagerError::ChecksumMismatch { .. })
+        ));
+    }
+}
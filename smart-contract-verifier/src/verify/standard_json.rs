@@ -0,0 +1,90 @@
+use foundry_compilers_new::{artifacts, solc::Solc};
+
+/// Parses a Solidity Standard JSON Input blob into a [`artifacts::SolcInput`].
+///
+/// Accepts the canonical object form
+/// (`{ "language": ..., "sources": {...}, "settings": {...} }`) as well as the
+/// stringified variants block explorers emit: a JSON string whose value is the
+/// escaped standard-json object, sometimes double-wrapped in an extra pair of
+/// braces (`{{...}}`). The wrapping is detected and stripped before the inner
+/// object is deserialized, so the optimizer runs, `evmVersion`, remappings and
+/// per-file output selection are preserved exactly as the explorer stored them.
+pub fn parse_standard_json(input: &str) -> Result<artifacts::SolcInput, StandardJsonError> {
+    let unwrapped = unwrap_input(input);
+    serde_json::from_str(&unwrapped).map_err(StandardJsonError::Deserialize)
+}
+
+/// Compiles a Standard JSON Input blob with `solc`, returning the raw compiler
+/// output exactly as [`async_compile_output`](Solc::async_compile_output) does.
+pub async fn compile_standard_json(
+    solc: &Solc,
+    input: &str,
+) -> Result<Vec<u8>, StandardJsonError> {
+    let input = parse_standard_json(input)?;
+    solc.async_compile_output(&input)
+        .await
+        .map_err(StandardJsonError::Compile)
+}
+
+/// Peels the explorer wrappers off a standard-json blob.
+///
+/// Explorers frequently persist the input as a JSON *string* rather than an
+/// object, and some double-wrap it in an extra `{...}` so the payload reads
+/// `{{ ... }}`. Both forms are normalized back to the bare standard-json
+/// object; anything already in object form is returned untouched.
+fn unwrap_input(input: &str) -> String {
+    let trimmed = input.trim();
+
+    // Double-brace wrapping: `{{ ... }}` → `{ ... }`.
+    if let Some(inner) = trimmed
+        .strip_prefix("{{")
+        .and_then(|s| s.strip_suffix("}}"))
+    {
+        return format!("{{{inner}}}");
+    }
+
+    // Stringified object: a JSON string literal whose contents are the object.
+    if trimmed.starts_with('"') {
+        if let Ok(serde_json::Value::String(inner)) = serde_json::from_str(trimmed) {
+            // The string's contents may themselves be double-brace wrapped.
+            return unwrap_input(&inner);
+        }
+    }
+
+    trimmed.to_string()
+}
+
+/// Errors raised while ingesting a Standard JSON Input blob.
+#[derive(Debug, thiserror::Error)]
+pub enum StandardJsonError {
+    #[error("failed to deserialize standard JSON input")]
+    Deserialize(#[source] serde_json::Error),
+    #[error("solc compilation failed")]
+    Compile(#[source] foundry_compilers_new::error::SolcError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OBJECT: &str = r#"{"language":"Solidity"}"#;
+
+    #[test]
+    fn bare_object_is_returned_untouched() {
+        assert_eq!(unwrap_input(OBJECT), OBJECT);
+        assert_eq!(unwrap_input(&format!("  {OBJECT}\n")), OBJECT);
+    }
+
+    #[test]
+    fn double_braced_object_is_unwrapped() {
+        let wrapped = r#"{{"language":"Solidity"}}"#;
+        assert_eq!(unwrap_input(wrapped), OBJECT);
+    }
+
+    #[test]
+    fn stringified_object_is_unwrapped() {
+        let stringified = serde_json::to_string(OBJECT).unwrap();
+        assert_ne!(stringified, OBJECT);
+        assert_eq!(unwrap_input(&stringified), OBJECT);
+    }
+}